@@ -1,7 +1,8 @@
 use eframe::egui;
+use globset::{Glob, GlobMatcher};
 use serde_json::Value;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 #[derive(Default)]
 struct JsonExplorer {
@@ -16,6 +17,222 @@ struct JsonExplorer {
     show_node_values: bool,
     // additional windows
     show_about_dialog: bool,
+    // JSONPath query bar
+    query_text: String,
+    query_results: Vec<Vec<String>>,
+    query_searched: bool,
+    // Tree search
+    search_text: String,
+    search_use_glob: bool,
+    search_matches_only: bool,
+    search_match_ids: HashSet<String>,
+    search_expand_ids: HashSet<String>,
+    // Raw JSON view format
+    json_format: JsonFormat,
+    // Workspace/folder mode
+    workspace_root: Option<PathBuf>,
+    workspace_files: Vec<PathBuf>,
+    workspace_filter: String,
+    // Value editing
+    selected_leaf_path: Option<Vec<String>>,
+    editor_value_text: String,
+    edit_error: Option<String>,
+    dirty: bool,
+}
+
+/// Maximum directory depth to recurse into when discovering workspace files,
+/// so a folder with a deep or cyclical structure can't hang discovery.
+const WORKSPACE_MAX_DEPTH: usize = 8;
+
+/// Output format for the Raw JSON view, mirroring the compiler's
+/// `json` vs `pretty-json` distinction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum JsonFormat {
+    #[default]
+    Pretty,
+    Compact,
+}
+
+/// A single step of a parsed JSONPath-subset expression.
+#[derive(Debug, Clone)]
+enum PathStep {
+    Key(String),
+    Index(usize),
+    Wildcard,
+    RecursiveDescent,
+}
+
+/// Tokenize a JSONPath-subset expression into steps.
+///
+/// Supports `$` (root), `.key`, `["key"]`, `[index]`, `[*]`, and `..`.
+/// Returns `None` if the expression doesn't start with `$` or contains a
+/// step that can't be parsed.
+fn tokenize_jsonpath(expr: &str) -> Option<Vec<PathStep>> {
+    let expr = expr.trim();
+    if !expr.starts_with('$') {
+        return None;
+    }
+
+    let chars: Vec<char> = expr[1..].chars().collect();
+    let mut steps = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                if i + 1 < chars.len() && chars[i + 1] == '.' {
+                    steps.push(PathStep::RecursiveDescent);
+                    i += 2;
+
+                    // `..key` recurses then filters by name, as opposed to
+                    // bare `..` which just recurses.
+                    let start = i;
+                    while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                        i += 1;
+                    }
+                    if i > start {
+                        steps.push(PathStep::Key(chars[start..i].iter().collect()));
+                    }
+                } else {
+                    i += 1;
+                    let start = i;
+                    while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                        i += 1;
+                    }
+                    if i > start {
+                        steps.push(PathStep::Key(chars[start..i].iter().collect()));
+                    }
+                }
+            }
+            '[' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != ']' {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return None;
+                }
+                let inner: String = chars[start..i].iter().collect();
+                i += 1; // skip ']'
+
+                if inner == "*" {
+                    steps.push(PathStep::Wildcard);
+                } else if inner.len() >= 2 && inner.starts_with('"') && inner.ends_with('"') {
+                    steps.push(PathStep::Key(inner[1..inner.len() - 1].to_string()));
+                } else if let Ok(index) = inner.parse::<usize>() {
+                    steps.push(PathStep::Index(index));
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(steps)
+}
+
+/// Walk `value` applying the remaining JSONPath `steps`, appending every
+/// matching node's path to `matches`. `path` is the accumulated path to
+/// `value` so far, using the same `Vec<String>` representation as
+/// `navigation_path`.
+fn walk_jsonpath(value: &Value, path: &[String], steps: &[PathStep], matches: &mut Vec<Vec<String>>) {
+    let Some((step, rest)) = steps.split_first() else {
+        matches.push(path.to_vec());
+        return;
+    };
+
+    match step {
+        PathStep::Key(key) => {
+            if let Value::Object(obj) = value {
+                if let Some(child) = obj.get(key) {
+                    let mut child_path = path.to_vec();
+                    child_path.push(key.clone());
+                    walk_jsonpath(child, &child_path, rest, matches);
+                }
+            }
+        }
+        PathStep::Index(index) => {
+            if let Value::Array(arr) = value {
+                if let Some(child) = arr.get(*index) {
+                    let mut child_path = path.to_vec();
+                    child_path.push(index.to_string());
+                    walk_jsonpath(child, &child_path, rest, matches);
+                }
+            }
+        }
+        PathStep::Wildcard => match value {
+            Value::Object(obj) => {
+                for (key, child) in obj {
+                    let mut child_path = path.to_vec();
+                    child_path.push(key.clone());
+                    walk_jsonpath(child, &child_path, rest, matches);
+                }
+            }
+            Value::Array(arr) => {
+                for (index, child) in arr.iter().enumerate() {
+                    let mut child_path = path.to_vec();
+                    child_path.push(index.to_string());
+                    walk_jsonpath(child, &child_path, rest, matches);
+                }
+            }
+            _ => {}
+        },
+        PathStep::RecursiveDescent => {
+            // The current node counts as a match for the remaining steps...
+            walk_jsonpath(value, path, rest, matches);
+            // ...and so does every descendant, still under `..`.
+            match value {
+                Value::Object(obj) => {
+                    for (key, child) in obj {
+                        let mut child_path = path.to_vec();
+                        child_path.push(key.clone());
+                        walk_jsonpath(child, &child_path, steps, matches);
+                    }
+                }
+                Value::Array(arr) => {
+                    for (index, child) in arr.iter().enumerate() {
+                        let mut child_path = path.to_vec();
+                        child_path.push(index.to_string());
+                        walk_jsonpath(child, &child_path, steps, matches);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The raw, unquoted text an editable field should start from for `value`.
+fn editable_value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Null => "null".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Walk `path` (object keys / array indices, same representation as
+/// `navigation_path`) from `root` and return a mutable reference to the
+/// node it leads to.
+fn value_at_path_mut<'a>(root: &'a mut Value, path: &[String]) -> Option<&'a mut Value> {
+    let mut current = root;
+    for key in path {
+        current = match current {
+            Value::Object(obj) => obj.get_mut(key)?,
+            Value::Array(arr) => {
+                // Array children are pushed onto tree paths in their display
+                // form ("[0]"), not the bare index `navigate_to_path` uses.
+                let index = key.strip_prefix('[').and_then(|s| s.strip_suffix(']')).unwrap_or(key);
+                arr.get_mut(index.parse::<usize>().ok()?)?
+            }
+            _ => return None,
+        };
+    }
+    Some(current)
 }
 
 impl JsonExplorer {
@@ -24,10 +241,162 @@ impl JsonExplorer {
             selected_json: String::new(),
             show_node_types: false,  // Default to not showing types
             show_node_values: false, // Default to not showing values
+            workspace_filter: "**/*.json".to_string(),
             ..Default::default()
         }
     }
 
+    /// Open `root` as a workspace, discovering every file matching
+    /// `workspace_filter` beneath it (up to `WORKSPACE_MAX_DEPTH`).
+    fn load_workspace(&mut self, root: PathBuf) {
+        self.workspace_files.clear();
+        let matcher = Glob::new(&self.workspace_filter).ok().map(|g| g.compile_matcher());
+        Self::collect_workspace_files(&root, matcher.as_ref(), 0, &mut self.workspace_files);
+        self.workspace_files.sort();
+        self.workspace_root = Some(root);
+    }
+
+    fn collect_workspace_files(
+        dir: &Path,
+        matcher: Option<&GlobMatcher>,
+        depth: usize,
+        files: &mut Vec<PathBuf>,
+    ) {
+        if depth > WORKSPACE_MAX_DEPTH {
+            return;
+        }
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                Self::collect_workspace_files(&path, matcher, depth + 1, files);
+            } else {
+                let matches = match matcher {
+                    Some(matcher) => matcher.is_match(&path),
+                    None => path.extension().is_some_and(|ext| ext == "json"),
+                };
+                if matches {
+                    files.push(path);
+                }
+            }
+        }
+    }
+
+    /// Evaluate `query_text` as a JSONPath-subset expression against
+    /// `root_data` and store the matching paths in `query_results`. Navigates
+    /// directly when there's exactly one match.
+    fn run_query(&mut self) {
+        self.query_results.clear();
+        self.query_searched = !self.query_text.trim().is_empty();
+
+        if let Some(root) = &self.root_data {
+            if let Some(steps) = tokenize_jsonpath(&self.query_text) {
+                let mut matches = Vec::new();
+                walk_jsonpath(root, &[], &steps, &mut matches);
+                self.query_results = matches;
+            }
+        }
+
+        if self.query_results.len() == 1 {
+            let path = self.query_results[0].clone();
+            self.navigate_to_path(path);
+        }
+    }
+
+    /// Recompute the set of nodes matching `search_text` (and, transitively,
+    /// the set of container nodes that need to be force-expanded to reveal
+    /// them) in one pass over `current_data`. Must be called whenever the
+    /// search query or mode changes, since matches have to be known before
+    /// `render_json_tree` decides what to draw.
+    fn recompute_search(&mut self) {
+        self.search_match_ids.clear();
+        self.search_expand_ids.clear();
+
+        let query = self.search_text.trim().to_string();
+        if query.is_empty() {
+            return;
+        }
+
+        let matcher = if self.search_use_glob {
+            Glob::new(&query).ok().map(|glob| glob.compile_matcher())
+        } else {
+            None
+        };
+
+        if let Some(data) = self.current_data.clone() {
+            self.collect_search_matches(&data, "", &[], &query, matcher.as_ref());
+        }
+
+        for node_id in self.search_expand_ids.clone() {
+            self.expanded_nodes.insert(node_id, true);
+        }
+    }
+
+    /// Recursive worker for `recompute_search`. Returns whether `value` or
+    /// any of its descendants matched, so callers can mark themselves as
+    /// needing to be expanded.
+    fn collect_search_matches(
+        &mut self,
+        value: &Value,
+        key: &str,
+        path: &[String],
+        query: &str,
+        matcher: Option<&GlobMatcher>,
+    ) -> bool {
+        let node_id = format!("{}_{}", path.join("_"), key);
+
+        let value_text = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Null => "null".to_string(),
+            Value::Object(_) | Value::Array(_) => String::new(),
+        };
+
+        let self_matches = if let Some(matcher) = matcher {
+            matcher.is_match(key) || matcher.is_match(&value_text)
+        } else {
+            let query_lower = query.to_lowercase();
+            key.to_lowercase().contains(&query_lower) || value_text.to_lowercase().contains(&query_lower)
+        };
+
+        let mut child_path = path.to_vec();
+        if !key.is_empty() {
+            child_path.push(key.to_string());
+        }
+
+        let mut has_match = self_matches;
+        match value {
+            Value::Object(obj) => {
+                for (k, v) in obj {
+                    if self.collect_search_matches(v, k, &child_path, query, matcher) {
+                        has_match = true;
+                    }
+                }
+            }
+            Value::Array(arr) => {
+                for (i, v) in arr.iter().enumerate() {
+                    if self.collect_search_matches(v, &format!("[{}]", i), &child_path, query, matcher) {
+                        has_match = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if self_matches {
+            self.search_match_ids.insert(node_id.clone());
+        }
+        if has_match && matches!(value, Value::Object(_) | Value::Array(_)) {
+            self.search_expand_ids.insert(node_id);
+        }
+
+        has_match
+    }
+
     fn load_file(&mut self, path: PathBuf) -> anyhow::Result<()> {
         let content = std::fs::read_to_string(&path)?;
         let data: Value = serde_json::from_str(&content)?;
@@ -37,6 +406,9 @@ impl JsonExplorer {
         self.current_file_path = Some(path);
         self.navigation_path.clear();
         self.expanded_nodes.clear();
+        self.selected_leaf_path = None;
+        self.edit_error = None;
+        self.dirty = false;
         self.update_selected_json();
 
         Ok(())
@@ -88,8 +460,87 @@ impl JsonExplorer {
 
     fn update_selected_json(&mut self) {
         if let Some(data) = &self.current_data {
-            self.selected_json = serde_json::to_string_pretty(data)
-                .unwrap_or_else(|_| "Error formatting JSON".to_string());
+            self.selected_json = self.format_json(data);
+        }
+    }
+
+    /// Serialize `value` using the currently selected View-menu format.
+    fn format_json(&self, value: &Value) -> String {
+        let result = match self.json_format {
+            JsonFormat::Pretty => serde_json::to_string_pretty(value),
+            JsonFormat::Compact => serde_json::to_string(value),
+        };
+        result.unwrap_or_else(|_| "Error formatting JSON".to_string())
+    }
+
+    /// Parse `editor_value_text` into a `Value` matching the type of the
+    /// currently selected leaf and write it back into `root_data`, then
+    /// re-derive `current_data` so the tree and Raw view stay consistent.
+    fn commit_edit(&mut self) {
+        let Some(path) = self.selected_leaf_path.clone() else {
+            return;
+        };
+        let Some(root) = self.root_data.as_mut() else {
+            return;
+        };
+        let Some(slot) = value_at_path_mut(root, &path) else {
+            return;
+        };
+
+        let new_value = match slot {
+            Value::String(_) => Some(Value::String(self.editor_value_text.clone())),
+            Value::Number(_) => {
+                let text = self.editor_value_text.trim();
+                text.parse::<i64>()
+                    .map(serde_json::Number::from)
+                    .or_else(|_| text.parse::<u64>().map(serde_json::Number::from))
+                    .ok()
+                    .or_else(|| text.parse::<f64>().ok().and_then(serde_json::Number::from_f64))
+                    .map(Value::Number)
+            }
+            Value::Bool(_) => match self.editor_value_text.trim() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            Value::Null => Some(Value::Null),
+            _ => None,
+        };
+
+        let Some(new_value) = new_value else {
+            self.edit_error = Some("Invalid value for this field's type".to_string());
+            return;
+        };
+
+        *slot = new_value;
+        self.edit_error = None;
+        self.dirty = true;
+        self.navigate_to_path(self.navigation_path.clone());
+    }
+
+    /// Serialize `root_data` to `path` in the current View-menu format.
+    fn save_to_path(&mut self, path: PathBuf) {
+        let Some(root) = &self.root_data else {
+            return;
+        };
+        let contents = self.format_json(root);
+
+        match std::fs::write(&path, contents) {
+            Ok(()) => {
+                self.current_file_path = Some(path);
+                self.dirty = false;
+            }
+            Err(e) => eprintln!("Error saving file: {}", e),
+        }
+    }
+
+    /// Render a tree row's label, highlighting it when it's a search match.
+    fn tree_label(&self, ui: &mut egui::Ui, text: String, is_match: bool) -> egui::Response {
+        if is_match {
+            let rich = egui::RichText::new(text).color(egui::Color32::BLACK).background_color(egui::Color32::YELLOW);
+            ui.selectable_label(false, rich)
+        } else {
+            ui.selectable_label(false, text)
         }
     }
 
@@ -104,6 +555,12 @@ impl JsonExplorer {
     fn render_json_tree(&mut self, ui: &mut egui::Ui, value: &Value, key: &str, path: Vec<String>) {
         let node_id = format!("{}_{}", path.join("_"), key);
 
+        let searching = !self.search_text.trim().is_empty();
+        let is_match = self.search_match_ids.contains(&node_id);
+        if searching && self.search_matches_only && !is_match && !self.search_expand_ids.contains(&node_id) {
+            return;
+        }
+
         match value {
             Value::Object(obj) => {
                 let is_expanded = *self.expanded_nodes.get(&node_id).unwrap_or(&false);
@@ -123,7 +580,7 @@ impl JsonExplorer {
                     format!("{} {}", icon, key)
                 };
 
-                let response = ui.selectable_label(false, display_text);
+                let response = self.tree_label(ui, display_text, is_match);
 
                 if response.clicked() {
                     self.expanded_nodes.insert(node_id.clone(), !is_expanded);
@@ -168,7 +625,7 @@ impl JsonExplorer {
                     format!("{} {}", icon, key)
                 };
 
-                let response = ui.selectable_label(false, display_text);
+                let response = self.tree_label(ui, display_text, is_match);
 
                 if response.clicked() {
                     self.expanded_nodes.insert(node_id.clone(), !is_expanded);
@@ -223,11 +680,22 @@ impl JsonExplorer {
                     format!("  {}", display_key)
                 };
 
-                let response = ui.selectable_label(false, display_text);
+                let response = self.tree_label(ui, display_text, is_match);
 
                 if response.clicked() {
-                    self.selected_json = serde_json::to_string_pretty(value)
-                        .unwrap_or_else(|_| "Error formatting JSON".to_string());
+                    self.selected_json = self.format_json(value);
+
+                    // `path`/`key` are relative to `current_data`; prepend
+                    // `navigation_path` so the stored path is root-relative,
+                    // matching what `value_at_path_mut` walks from `root_data`.
+                    let mut leaf_path = self.navigation_path.clone();
+                    leaf_path.extend(path.clone());
+                    if !key.is_empty() {
+                        leaf_path.push(key.to_string());
+                    }
+                    self.editor_value_text = editable_value_text(value);
+                    self.selected_leaf_path = Some(leaf_path);
+                    self.edit_error = None;
                 }
             }
         }
@@ -236,6 +704,11 @@ impl JsonExplorer {
 
 impl eframe::App for JsonExplorer {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        ctx.send_viewport_cmd(egui::ViewportCommand::Title(format!(
+            "JSON Explorer — {}",
+            self.get_current_path_string()
+        )));
+
         // Menu bar
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
@@ -251,6 +724,28 @@ impl eframe::App for JsonExplorer {
                         }
                         ui.close_menu();
                     }
+                    if ui.button("Open Folder").clicked() {
+                        if let Some(folder) = rfd::FileDialog::new().pick_folder() {
+                            self.load_workspace(folder);
+                        }
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui
+                        .add_enabled(self.current_file_path.is_some(), egui::Button::new("Save"))
+                        .clicked()
+                    {
+                        if let Some(path) = self.current_file_path.clone() {
+                            self.save_to_path(path);
+                        }
+                        ui.close_menu();
+                    }
+                    if ui.button("Save As…").clicked() {
+                        if let Some(path) = rfd::FileDialog::new().add_filter("JSON", &["json"]).save_file() {
+                            self.save_to_path(path);
+                        }
+                        ui.close_menu();
+                    }
                     ui.separator();
                     if ui.button("Exit").clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
@@ -260,6 +755,16 @@ impl eframe::App for JsonExplorer {
                 ui.menu_button("View", |ui| {
                     ui.checkbox(&mut self.show_node_types, "Show Node Types");
                     ui.checkbox(&mut self.show_node_values, "Show Node Values");
+                    ui.separator();
+                    let format_changed = ui
+                        .radio_value(&mut self.json_format, JsonFormat::Pretty, "Pretty JSON")
+                        .changed()
+                        || ui
+                            .radio_value(&mut self.json_format, JsonFormat::Compact, "Compact JSON")
+                            .changed();
+                    if format_changed {
+                        self.update_selected_json();
+                    }
                 });
 
                 ui.menu_button("Help", |ui| {
@@ -292,7 +797,19 @@ impl eframe::App for JsonExplorer {
 
                 ui.separator();
 
-                ui.label(format!("Path: {}", self.get_current_path_string()));
+                let mut navigate_to = None;
+                if ui.button("Root").clicked() {
+                    navigate_to = Some(Vec::new());
+                }
+                for (i, segment) in self.navigation_path.iter().enumerate() {
+                    ui.label("→");
+                    if ui.button(segment).clicked() {
+                        navigate_to = Some(self.navigation_path[..=i].to_vec());
+                    }
+                }
+                if let Some(path) = navigate_to {
+                    self.navigate_to_path(path);
+                }
 
                 if let Some(file_path) = &self.current_file_path {
                     ui.separator();
@@ -302,8 +819,97 @@ impl eframe::App for JsonExplorer {
                     ));
                 }
             });
+
+            ui.horizontal(|ui| {
+                ui.label("Query:");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query_text)
+                        .hint_text("$.key[0][*]..deep")
+                        .desired_width(250.0),
+                );
+                if response.changed() {
+                    self.run_query();
+                }
+
+                match self.query_results.len() {
+                    0 if self.query_searched => {
+                        ui.label("no matches");
+                    }
+                    1 => {
+                        ui.label("1 match (navigated)");
+                    }
+                    n if n > 1 => {
+                        ui.label(format!("{} matches:", n));
+                        egui::ScrollArea::horizontal()
+                            .id_salt("query_results_scroll")
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    for path in self.query_results.clone() {
+                                        let label = if path.is_empty() {
+                                            "Root".to_string()
+                                        } else {
+                                            path.join(" → ")
+                                        };
+                                        if ui.selectable_label(false, label).clicked() {
+                                            self.navigate_to_path(path);
+                                        }
+                                    }
+                                });
+                            });
+                    }
+                    _ => {}
+                }
+            });
         });
 
+        egui::SidePanel::left("file_panel")
+            .min_width(180.0)
+            .frame(egui::Frame::default().inner_margin(egui::Margin::same(8)))
+            .show(ctx, |ui| {
+                ui.heading("Workspace");
+                ui.separator();
+
+                if ui.button("Open Folder").clicked() {
+                    if let Some(folder) = rfd::FileDialog::new().pick_folder() {
+                        self.load_workspace(folder);
+                    }
+                }
+
+                if let Some(root) = self.workspace_root.clone() {
+                    ui.separator();
+                    ui.label(format!(
+                        "Root: {}",
+                        root.file_name().unwrap_or_default().to_string_lossy()
+                    ));
+
+                    let filter_response = ui.add(
+                        egui::TextEdit::singleline(&mut self.workspace_filter)
+                            .hint_text("**/*.json")
+                            .desired_width(160.0),
+                    );
+                    if filter_response.changed() {
+                        self.load_workspace(root);
+                    }
+
+                    ui.separator();
+                    egui::ScrollArea::vertical()
+                        .id_salt("workspace_files_scroll")
+                        .show(ui, |ui| {
+                            for file in self.workspace_files.clone() {
+                                let is_active = self.current_file_path.as_deref() == Some(file.as_path());
+                                let name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+                                if ui.selectable_label(is_active, name).clicked() {
+                                    if let Err(e) = self.load_file(file) {
+                                        eprintln!("Error loading file: {}", e);
+                                    }
+                                }
+                            }
+                        });
+                } else {
+                    ui.label("No folder opened");
+                }
+            });
+
         egui::SidePanel::left("tree_panel")
             .min_width(400.0)
             .frame(egui::Frame::default().inner_margin(egui::Margin::same(8)))
@@ -311,6 +917,23 @@ impl eframe::App for JsonExplorer {
                 ui.heading("JSON Structure");
                 ui.separator();
 
+                ui.horizontal(|ui| {
+                    ui.label("Search:");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.search_text)
+                            .hint_text("key or value…")
+                            .desired_width(150.0),
+                    );
+                    let glob_toggled = ui.checkbox(&mut self.search_use_glob, "Glob").changed();
+                    let matches_only_toggled =
+                        ui.checkbox(&mut self.search_matches_only, "Matches only").changed();
+
+                    if response.changed() || glob_toggled || matches_only_toggled {
+                        self.recompute_search();
+                    }
+                });
+                ui.separator();
+
                 egui::ScrollArea::vertical().show(ui, |ui| {
                     ui.style_mut().override_text_style = Some(egui::TextStyle::Monospace);
                     if let Some(data) = self.current_data.clone() {
@@ -321,10 +944,47 @@ impl eframe::App for JsonExplorer {
                 });
             });
 
+        if self.selected_leaf_path.is_some() {
+            egui::TopBottomPanel::bottom("edit_panel").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Edit Value:");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.editor_value_text).desired_width(300.0),
+                    );
+                    let committed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Apply").clicked() || committed {
+                        self.commit_edit();
+                    }
+                    if self.dirty {
+                        ui.label("● unsaved changes");
+                    }
+                });
+                if let Some(error) = &self.edit_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            });
+        }
+
         egui::CentralPanel::default()
             .frame(egui::Frame::default().inner_margin(egui::Margin::same(8)))
             .show(ctx, |ui| {
-                ui.heading("Raw JSON View");
+                ui.horizontal(|ui| {
+                    ui.heading("Raw JSON View");
+                    if ui.button("Copy to Clipboard").clicked() {
+                        ctx.copy_text(self.selected_json.clone());
+                    }
+                    if ui.button("Save Selection As…").clicked() {
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter("JSON", &["json"])
+                            .set_file_name("selection.json")
+                            .save_file()
+                        {
+                            if let Err(e) = std::fs::write(&path, &self.selected_json) {
+                                eprintln!("Error saving selection: {}", e);
+                            }
+                        }
+                    }
+                });
                 ui.separator();
 
                 egui::ScrollArea::both().show(ui, |ui| {